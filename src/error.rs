@@ -0,0 +1,30 @@
+use std::fmt;
+
+/// The error type used throughout this crate.
+#[derive(Debug)]
+pub enum Error {
+    /// Something went wrong encoding or decoding data.
+    Encoding(String),
+    /// Any other error that doesn't fit a more specific variant.
+    Opaque(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Encoding(s) => write!(f, "Encoding: {}", s),
+            Error::Opaque(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Error {
+        Error::Encoding(err.to_string())
+    }
+}
+
+/// Helper alias for `Result`s that return `in_toto` `Error`s.
+pub type Result<T> = std::result::Result<T, Error>;
@@ -0,0 +1,311 @@
+//! A canonical CBOR (RFC 8949 §4.2.1) [`DataInterchange`].
+//!
+//! Predicates are signed, so two encoders must agree byte-for-byte on the
+//! same logical document. Canonical CBOR pins that down: map keys are
+//! ordered by their own encoded bytes, every array/map uses definite-length
+//! encoding, and every integer uses the smallest encoding that represents
+//! it. Non-integer numbers are always written as 64-bit floats; they are
+//! not shortened to a 16- or 32-bit encoding.
+
+use serde_json::{Number, Value};
+
+use super::DataInterchange;
+use crate::{Error, Result};
+
+/// Canonical CBOR, for DSSE payloads that need a smaller or non-JSON
+/// encoding than [`super::Json`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cbor;
+
+impl DataInterchange for Cbor {
+    type RawData = Value;
+
+    fn canonicalize(raw_data: &Value) -> Result<Vec<u8>> {
+        let mut out = Vec::new();
+        encode(raw_data, &mut out)?;
+        Ok(out)
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Value> {
+        let mut pos = 0;
+        let value = decode(bytes, &mut pos, 0)?;
+        if pos != bytes.len() {
+            return Err(Error::Encoding("trailing bytes after CBOR value".into()));
+        }
+        Ok(value)
+    }
+
+    fn serialize<T: serde::Serialize>(data: &T) -> Result<Value> {
+        Ok(serde_json::to_value(data)?)
+    }
+
+    fn deserialize<T: serde::de::DeserializeOwned>(raw_data: &Value) -> Result<T> {
+        Ok(serde_json::from_value(raw_data.clone())?)
+    }
+}
+
+// Major types, per RFC 8949 §3.
+const MAJOR_UNSIGNED: u8 = 0;
+const MAJOR_NEGATIVE: u8 = 1;
+const MAJOR_TEXT: u8 = 3;
+const MAJOR_ARRAY: u8 = 4;
+const MAJOR_MAP: u8 = 5;
+const MAJOR_SIMPLE: u8 = 7;
+
+fn write_head(out: &mut Vec<u8>, major: u8, n: u64) {
+    let major = major << 5;
+    if n < 24 {
+        out.push(major | n as u8);
+    } else if n <= u8::MAX as u64 {
+        out.push(major | 24);
+        out.push(n as u8);
+    } else if n <= u16::MAX as u64 {
+        out.push(major | 25);
+        out.extend_from_slice(&(n as u16).to_be_bytes());
+    } else if n <= u32::MAX as u64 {
+        out.push(major | 26);
+        out.extend_from_slice(&(n as u32).to_be_bytes());
+    } else {
+        out.push(major | 27);
+        out.extend_from_slice(&n.to_be_bytes());
+    }
+}
+
+fn encode_number(n: &Number, out: &mut Vec<u8>) -> Result<()> {
+    if let Some(u) = n.as_u64() {
+        write_head(out, MAJOR_UNSIGNED, u);
+    } else if let Some(i) = n.as_i64() {
+        // Negative integers encode `-1 - n` under major type 1.
+        write_head(out, MAJOR_NEGATIVE, i.unsigned_abs() - 1);
+    } else if let Some(f) = n.as_f64() {
+        out.push((MAJOR_SIMPLE << 5) | 27);
+        out.extend_from_slice(&f.to_bits().to_be_bytes());
+    } else {
+        return Err(Error::Encoding(format!("number out of range: {}", n)));
+    }
+    Ok(())
+}
+
+fn encode(value: &Value, out: &mut Vec<u8>) -> Result<()> {
+    match value {
+        Value::Null => out.push((MAJOR_SIMPLE << 5) | 22),
+        Value::Bool(false) => out.push((MAJOR_SIMPLE << 5) | 20),
+        Value::Bool(true) => out.push((MAJOR_SIMPLE << 5) | 21),
+        Value::Number(n) => encode_number(n, out)?,
+        Value::String(s) => {
+            write_head(out, MAJOR_TEXT, s.len() as u64);
+            out.extend_from_slice(s.as_bytes());
+        }
+        Value::Array(items) => {
+            write_head(out, MAJOR_ARRAY, items.len() as u64);
+            for item in items {
+                encode(item, out)?;
+            }
+        }
+        Value::Object(map) => {
+            // Canonical ordering: by each key's own encoded bytes.
+            let mut entries: Vec<(Vec<u8>, &Value)> = Vec::with_capacity(map.len());
+            for (key, val) in map {
+                let mut key_bytes = Vec::new();
+                write_head(&mut key_bytes, MAJOR_TEXT, key.len() as u64);
+                key_bytes.extend_from_slice(key.as_bytes());
+                entries.push((key_bytes, val));
+            }
+            entries.sort_by(|a, b| a.0.cmp(&b.0));
+
+            write_head(out, MAJOR_MAP, entries.len() as u64);
+            for (key_bytes, val) in entries {
+                out.extend_from_slice(&key_bytes);
+                encode(val, out)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+// Mirrors `serde_json`'s default recursion limit: bytes are attacker
+// controlled (DSSE payloads, unverified predicates), so nesting has to be
+// bounded or a crafted document can blow the stack.
+const MAX_DEPTH: usize = 128;
+
+fn read_uint(bytes: &[u8], pos: &mut usize, additional: u8) -> Result<u64> {
+    let n = match additional {
+        0..=23 => additional as u64,
+        24 => {
+            let v = *bytes
+                .get(*pos)
+                .ok_or_else(|| Error::Encoding("truncated CBOR integer".into()))?;
+            *pos += 1;
+            v as u64
+        }
+        25 => {
+            let slice = take(bytes, pos, 2)?;
+            u16::from_be_bytes(slice.try_into().unwrap()) as u64
+        }
+        26 => {
+            let slice = take(bytes, pos, 4)?;
+            u32::from_be_bytes(slice.try_into().unwrap()) as u64
+        }
+        27 => {
+            let slice = take(bytes, pos, 8)?;
+            u64::from_be_bytes(slice.try_into().unwrap())
+        }
+        _ => return Err(Error::Encoding("unsupported CBOR length encoding".into())),
+    };
+    Ok(n)
+}
+
+/// Slice `count` bytes starting at `*pos` and advance `*pos`, without letting
+/// the bounds check itself overflow on an attacker-chosen `count`.
+fn take<'a>(bytes: &'a [u8], pos: &mut usize, count: usize) -> Result<&'a [u8]> {
+    let end = pos
+        .checked_add(count)
+        .ok_or_else(|| Error::Encoding("CBOR length overflows usize".into()))?;
+    let slice = bytes
+        .get(*pos..end)
+        .ok_or_else(|| Error::Encoding("truncated CBOR value".into()))?;
+    *pos = end;
+    Ok(slice)
+}
+
+fn decode(bytes: &[u8], pos: &mut usize, depth: usize) -> Result<Value> {
+    if depth >= MAX_DEPTH {
+        return Err(Error::Encoding("CBOR document nested too deeply".into()));
+    }
+
+    let head = *bytes
+        .get(*pos)
+        .ok_or_else(|| Error::Encoding("unexpected end of CBOR input".into()))?;
+    *pos += 1;
+    let major = head >> 5;
+    let additional = head & 0x1f;
+
+    match major {
+        0 => Ok(Value::Number(read_uint(bytes, pos, additional)?.into())),
+        1 => {
+            let n = read_uint(bytes, pos, additional)?;
+            Ok(Value::Number((-1i64 - n as i64).into()))
+        }
+        3 => {
+            let len = read_uint(bytes, pos, additional)? as usize;
+            let slice = take(bytes, pos, len)?;
+            let s = std::str::from_utf8(slice)
+                .map_err(|e| Error::Encoding(format!("invalid UTF-8 in CBOR string: {}", e)))?;
+            Ok(Value::String(s.to_string()))
+        }
+        4 => {
+            let len = read_uint(bytes, pos, additional)? as usize;
+            let mut items = Vec::new();
+            for _ in 0..len {
+                items.push(decode(bytes, pos, depth + 1)?);
+            }
+            Ok(Value::Array(items))
+        }
+        5 => {
+            let len = read_uint(bytes, pos, additional)? as usize;
+            let mut map = serde_json::Map::new();
+            for _ in 0..len {
+                let key = match decode(bytes, pos, depth + 1)? {
+                    Value::String(s) => s,
+                    _ => return Err(Error::Encoding("CBOR map key must be a text string".into())),
+                };
+                let val = decode(bytes, pos, depth + 1)?;
+                if map.contains_key(&key) {
+                    return Err(Error::Encoding(format!("duplicate key `{}` in CBOR map", key)));
+                }
+                map.insert(key, val);
+            }
+            Ok(Value::Object(map))
+        }
+        7 => match additional {
+            20 => Ok(Value::Bool(false)),
+            21 => Ok(Value::Bool(true)),
+            22 => Ok(Value::Null),
+            27 => {
+                let slice = take(bytes, pos, 8)?;
+                let bits = u64::from_be_bytes(slice.try_into().unwrap());
+                Ok(Value::Number(
+                    Number::from_f64(f64::from_bits(bits))
+                        .ok_or_else(|| Error::Encoding("non-finite CBOR float".into()))?,
+                ))
+            }
+            _ => Err(Error::Encoding(format!(
+                "unsupported CBOR simple value: {}",
+                additional
+            ))),
+        },
+        _ => Err(Error::Encoding(format!("unsupported CBOR major type: {}", major))),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn round_trips_through_canonicalize_and_from_bytes() {
+        let value = json!({
+            "b": 1,
+            "a": [true, false, null, -1, 256, "hi"],
+        });
+
+        let bytes = Cbor::canonicalize(&value).unwrap();
+        let decoded = Cbor::from_bytes(&bytes).unwrap();
+
+        assert_eq!(decoded, value);
+    }
+
+    #[test]
+    fn map_keys_are_canonically_ordered() {
+        let value = json!({"bb": 1, "a": 2, "c": 3});
+        let bytes = Cbor::canonicalize(&value).unwrap();
+
+        // Map header (3 pairs) then keys in order "a", "c", "bb": shorter
+        // encoded keys sort first.
+        assert_eq!(bytes[0], (MAJOR_MAP << 5) | 3);
+        assert_eq!(&bytes[1..3], &[(MAJOR_TEXT << 5) | 1, b'a']);
+    }
+
+    #[test]
+    fn small_integers_use_smallest_encoding() {
+        let bytes = Cbor::canonicalize(&json!(10)).unwrap();
+        assert_eq!(bytes, vec![10]);
+
+        let bytes = Cbor::canonicalize(&json!(-10)).unwrap();
+        assert_eq!(bytes, vec![(MAJOR_NEGATIVE << 5) | 9]);
+    }
+
+    #[test]
+    fn rejects_overflowing_length_header_instead_of_panicking() {
+        // Major type 3 (text string), additional 27: an 8-byte length follows.
+        let mut bytes = vec![(MAJOR_TEXT << 5) | 27];
+        bytes.extend_from_slice(&u64::MAX.to_be_bytes());
+
+        assert!(Cbor::from_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn rejects_deeply_nested_arrays_instead_of_overflowing_stack() {
+        let mut bytes = Vec::new();
+        for _ in 0..(MAX_DEPTH + 1) {
+            bytes.push((MAJOR_ARRAY << 5) | 1);
+        }
+        bytes.push((MAJOR_SIMPLE << 5) | 22);
+
+        assert!(Cbor::from_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn rejects_duplicate_map_key() {
+        // Map with 2 pairs: "a" -> true, "a" -> false.
+        let mut bytes = vec![(MAJOR_MAP << 5) | 2];
+        for value in [true, false] {
+            bytes.push((MAJOR_TEXT << 5) | 1);
+            bytes.push(b'a');
+            bytes.push((MAJOR_SIMPLE << 5) | if value { 21 } else { 20 });
+        }
+
+        assert!(Cbor::from_bytes(&bytes).is_err());
+    }
+}
@@ -0,0 +1,65 @@
+//! Interchange formats used to serialize in-toto metadata in a canonical,
+//! signature-stable way.
+
+mod cbor;
+mod strict_json;
+
+pub use cbor::Cbor;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::Result;
+
+/// A data interchange format that can canonicalize a value so the resulting
+/// bytes are stable and therefore safe to sign.
+pub trait DataInterchange: Clone {
+    /// The intermediate representation used while canonicalizing.
+    type RawData: Serialize + DeserializeOwned + Clone + PartialEq;
+
+    /// Canonicalize `raw_data` into its final, signable byte representation.
+    fn canonicalize(raw_data: &Self::RawData) -> Result<Vec<u8>>;
+
+    /// Parse a document in this interchange's wire format back into its
+    /// intermediate representation.
+    fn from_bytes(bytes: &[u8]) -> Result<Self::RawData>;
+
+    /// Serialize `data` into this interchange's intermediate representation.
+    fn serialize<T: Serialize>(data: &T) -> Result<Self::RawData>;
+
+    /// Deserialize `raw_data` from this interchange's intermediate representation.
+    fn deserialize<T: DeserializeOwned>(raw_data: &Self::RawData) -> Result<T>;
+}
+
+/// Canonical JSON, as used by the original in-toto link predicates.
+///
+/// `serde_json::Value` keeps its object keys in a `BTreeMap`, so a plain
+/// `serde_json::to_vec` already yields keys sorted lexicographically with no
+/// extraneous whitespace, which is all canonical JSON requires here.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Json;
+
+impl DataInterchange for Json {
+    type RawData = Value;
+
+    fn canonicalize(raw_data: &Value) -> Result<Vec<u8>> {
+        Ok(serde_json::to_vec(raw_data)?)
+    }
+
+    /// Predicates are security-sensitive, so parsing rejects a document
+    /// outright if any object in it - at any nesting level - repeats a key,
+    /// rather than silently keeping the last value as `serde_json` does by
+    /// default.
+    fn from_bytes(bytes: &[u8]) -> Result<Value> {
+        strict_json::parse(bytes)
+    }
+
+    fn serialize<T: Serialize>(data: &T) -> Result<Value> {
+        Ok(serde_json::to_value(data)?)
+    }
+
+    fn deserialize<T: DeserializeOwned>(raw_data: &Value) -> Result<T> {
+        Ok(serde_json::from_value(raw_data.clone())?)
+    }
+}
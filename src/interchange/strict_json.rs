@@ -0,0 +1,167 @@
+//! A JSON value parser that rejects duplicate object keys.
+//!
+//! `serde_json::Value`'s `Deserialize` impl silently keeps the last of two
+//! duplicate keys in an object. Predicates are security-sensitive, so a
+//! crafted attestation could smuggle a second `name`, `command`, or digest
+//! past whatever validated the first one. [`StrictValue`] parses the same
+//! grammar as `serde_json::Value`, but errors out the moment an object
+//! contains a key it has already seen, at any nesting level. This backs
+//! [`super::Json`], so strictness is a property of the JSON interchange
+//! itself rather than of which entry point a caller happens to use.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use serde::de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde_json::{Number, Value};
+
+use crate::{Error, Result};
+
+#[derive(Debug, Clone, PartialEq)]
+enum StrictValue {
+    Null,
+    Bool(bool),
+    Number(Number),
+    String(String),
+    Array(Vec<StrictValue>),
+    Object(BTreeMap<String, StrictValue>),
+}
+
+impl From<StrictValue> for Value {
+    fn from(value: StrictValue) -> Value {
+        match value {
+            StrictValue::Null => Value::Null,
+            StrictValue::Bool(b) => Value::Bool(b),
+            StrictValue::Number(n) => Value::Number(n),
+            StrictValue::String(s) => Value::String(s),
+            StrictValue::Array(a) => Value::Array(a.into_iter().map(Value::from).collect()),
+            StrictValue::Object(o) => {
+                Value::Object(o.into_iter().map(|(k, v)| (k, Value::from(v))).collect())
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for StrictValue {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(StrictValueVisitor)
+    }
+}
+
+struct StrictValueVisitor;
+
+impl<'de> Visitor<'de> for StrictValueVisitor {
+    type Value = StrictValue;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a JSON value with no duplicate object keys")
+    }
+
+    fn visit_bool<E>(self, v: bool) -> std::result::Result<Self::Value, E> {
+        Ok(StrictValue::Bool(v))
+    }
+
+    fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E> {
+        Ok(StrictValue::Number(v.into()))
+    }
+
+    fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E> {
+        Ok(StrictValue::Number(v.into()))
+    }
+
+    fn visit_f64<E>(self, v: f64) -> std::result::Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Number::from_f64(v)
+            .map(StrictValue::Number)
+            .ok_or_else(|| de::Error::custom("non-finite float"))
+    }
+
+    fn visit_str<E>(self, v: &str) -> std::result::Result<Self::Value, E> {
+        Ok(StrictValue::String(v.to_owned()))
+    }
+
+    fn visit_string<E>(self, v: String) -> std::result::Result<Self::Value, E> {
+        Ok(StrictValue::String(v))
+    }
+
+    fn visit_unit<E>(self) -> std::result::Result<Self::Value, E> {
+        Ok(StrictValue::Null)
+    }
+
+    fn visit_none<E>(self) -> std::result::Result<Self::Value, E> {
+        Ok(StrictValue::Null)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> std::result::Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Deserialize::deserialize(deserializer)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> std::result::Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut elements = Vec::new();
+        while let Some(element) = seq.next_element()? {
+            elements.push(element);
+        }
+        Ok(StrictValue::Array(elements))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> std::result::Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut object = BTreeMap::new();
+        while let Some(key) = map.next_key::<String>()? {
+            if object.contains_key(&key) {
+                return Err(de::Error::custom(format!(
+                    "duplicate key `{}` in object",
+                    key
+                )));
+            }
+            let value: StrictValue = map.next_value()?;
+            object.insert(key, value);
+        }
+        Ok(StrictValue::Object(object))
+    }
+}
+
+/// Parse `bytes` as JSON into a [`Value`], rejecting the document outright if
+/// any object in it - at any nesting level - repeats a key.
+pub(crate) fn parse(bytes: &[u8]) -> Result<Value> {
+    let strict: StrictValue =
+        serde_json::from_slice(bytes).map_err(|e| Error::Encoding(e.to_string()))?;
+    Ok(Value::from(strict))
+}
+
+#[cfg(test)]
+mod test {
+    use super::parse;
+
+    #[test]
+    fn rejects_duplicate_top_level_key() {
+        let doc = r#"{"name": "a", "name": "b"}"#;
+        assert!(parse(doc.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn rejects_duplicate_nested_key() {
+        let doc = r#"{"byproducts": {"stdout": "a", "stdout": "b"}}"#;
+        assert!(parse(doc.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn accepts_document_without_duplicates() {
+        let doc = r#"{"name": "a", "materials": {}, "env": null}"#;
+        let result = parse(doc.as_bytes()).unwrap();
+        assert_eq!(result["name"], "a");
+    }
+}
@@ -0,0 +1,7 @@
+//! A Rust implementation of the [in-toto](https://in-toto.io) specification.
+
+pub mod error;
+pub mod interchange;
+pub mod models;
+
+pub use crate::error::{Error, Result};
@@ -0,0 +1,24 @@
+use serde_derive::{Deserialize, Serialize};
+
+/// Captures the observable output of the command a link records: its return
+/// code, and what it wrote to `stdout`/`stderr`.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct ByProducts {
+    #[serde(rename = "return-value", skip_serializing_if = "Option::is_none")]
+    return_value: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stderr: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stdout: Option<String>,
+}
+
+impl ByProducts {
+    pub fn new() -> Self {
+        ByProducts {
+            return_value: Some(0),
+            stderr: Some("".into()),
+            stdout: Some("".into()),
+        }
+    }
+}
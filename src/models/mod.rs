@@ -0,0 +1,54 @@
+//! Types describing the metadata in-toto attestations are built from.
+
+pub mod byproducts;
+pub mod predicate;
+pub mod step;
+
+#[cfg(test)]
+pub mod test;
+
+use std::collections::BTreeMap;
+
+pub use predicate::{PredicateLayout, PredicateVersion, PredicateWrapper};
+
+use byproducts::ByProducts;
+use step::Command;
+
+/// The path to a target, relative to the root of the repository being linked.
+pub type VirtualTargetPath = String;
+
+/// The hashes recorded for a given [`VirtualTargetPath`].
+pub type TargetDescription = BTreeMap<String, String>;
+
+/// The metadata produced by running a step: the materials it consumed, the
+/// command it ran, and the byproducts/output it produced.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinkMetadata {
+    name: String,
+    materials: BTreeMap<VirtualTargetPath, TargetDescription>,
+    env: Option<BTreeMap<String, String>>,
+    command: Command,
+    byproducts: ByProducts,
+}
+
+impl LinkMetadata {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn materials(&self) -> &BTreeMap<VirtualTargetPath, TargetDescription> {
+        &self.materials
+    }
+
+    pub fn env(&self) -> &Option<BTreeMap<String, String>> {
+        &self.env
+    }
+
+    pub fn command(&self) -> &Command {
+        &self.command
+    }
+
+    pub fn byproducts(&self) -> &ByProducts {
+        &self.byproducts
+    }
+}
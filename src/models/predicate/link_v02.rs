@@ -3,7 +3,7 @@ use std::collections::BTreeMap;
 use serde_derive::{Deserialize, Serialize};
 
 use super::{PredicateLayout, PredicateVersion, PredicateWrapper};
-use crate::interchange::{DataInterchange, Json};
+use crate::interchange::Json;
 use crate::models::byproducts::ByProducts;
 use crate::models::step::Command;
 use crate::models::{LinkMetadata, TargetDescription, VirtualTargetPath};
@@ -37,7 +37,7 @@ impl From<LinkMetadata> for LinkV02 {
 
 impl PredicateLayout for LinkV02 {
     fn to_bytes(&self) -> Result<Vec<u8>> {
-        Json::canonicalize(&Json::serialize(self)?)
+        self.to_bytes_with::<Json>()
     }
 
     fn into_enum(self: Box<Self>) -> PredicateWrapper {
@@ -59,7 +59,7 @@ pub mod test {
 
     use super::LinkV02;
     use crate::{
-        interchange::{DataInterchange, Json},
+        interchange::{Cbor, DataInterchange, Json},
         models::{
             byproducts::ByProducts, test::BLANK_META, PredicateLayout, PredicateVersion,
             PredicateWrapper,
@@ -103,12 +103,20 @@ pub mod test {
 
     #[test]
     fn create_predicate_from_meta() {
-        let predicate = PredicateWrapper::from_meta(BLANK_META.clone(), PredicateVersion::LinkV0_2);
+        let predicate =
+            PredicateWrapper::from_meta(BLANK_META.clone(), PredicateVersion::LinkV0_2).unwrap();
         let real = Box::new(PREDICATE_LINK_V02.clone()).into_enum();
 
         assert_eq!(predicate, real);
     }
 
+    #[test]
+    fn from_meta_rejects_non_link_version() {
+        let predicate = PredicateWrapper::from_meta(BLANK_META.clone(), PredicateVersion::ScaiV0_2);
+
+        assert!(predicate.is_err());
+    }
+
     #[test]
     fn serialize_predicate() {
         let predicate = Box::new(PREDICATE_LINK_V02.clone()).into_enum();
@@ -152,4 +160,62 @@ pub mod test {
             assert!(predicate.is_err());
         }
     }
+
+    #[test]
+    fn deserialize_rejects_duplicate_top_level_key() {
+        let doc = r#"{
+            "name": "first",
+            "name": "second",
+            "materials": {},
+            "env": null,
+            "command": "",
+            "byproducts": {"return-value": 0, "stderr": "", "stdout": ""}
+        }"#;
+
+        let predicate = PredicateWrapper::from_bytes(doc.as_bytes(), PredicateVersion::LinkV0_2);
+
+        assert!(predicate.is_err());
+    }
+
+    #[test]
+    fn deserialize_rejects_duplicate_nested_key() {
+        let doc = r#"{
+            "name": "",
+            "materials": {},
+            "env": null,
+            "command": "",
+            "byproducts": {"return-value": 0, "stderr": "first", "stderr": "second", "stdout": ""}
+        }"#;
+
+        let predicate = PredicateWrapper::from_bytes(doc.as_bytes(), PredicateVersion::LinkV0_2);
+
+        assert!(predicate.is_err());
+    }
+
+    #[test]
+    fn from_bytes_with_json_rejects_duplicate_top_level_key() {
+        let doc = r#"{
+            "name": "first",
+            "name": "second",
+            "materials": {},
+            "env": null,
+            "command": "",
+            "byproducts": {"return-value": 0, "stderr": "", "stdout": ""}
+        }"#;
+
+        let predicate =
+            PredicateWrapper::from_bytes_with::<Json>(doc.as_bytes(), PredicateVersion::LinkV0_2);
+
+        assert!(predicate.is_err());
+    }
+
+    #[test]
+    fn round_trips_through_cbor() {
+        let bytes = PREDICATE_LINK_V02.to_bytes_with::<Cbor>().unwrap();
+        let predicate =
+            PredicateWrapper::from_bytes_with::<Cbor>(&bytes, PredicateVersion::LinkV0_2).unwrap();
+        let real = Box::new(PREDICATE_LINK_V02.clone()).into_enum();
+
+        assert_eq!(predicate, real);
+    }
 }
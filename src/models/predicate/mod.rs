@@ -0,0 +1,155 @@
+//! The predicate subsystem: the different payload schemas an in-toto
+//! attestation statement can carry.
+
+pub mod link_v02;
+pub mod resource_descriptor;
+pub mod sbom;
+pub mod scai_v02;
+pub mod slsa_provenance_v1;
+
+pub use link_v02::LinkV02;
+pub use resource_descriptor::ResourceDescriptor;
+pub use sbom::{SbomPredicate, SpdxDocument};
+pub use scai_v02::ScaiV02;
+pub use slsa_provenance_v1::SLSAProvenanceV1;
+
+use serde::Serialize;
+
+use crate::interchange::{DataInterchange, Json};
+use crate::models::LinkMetadata;
+use crate::{Error, Result};
+
+/// The predicate schemas this crate knows how to parse and canonicalize.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PredicateVersion {
+    LinkV0_2,
+    SLSAProvenanceV1,
+    ScaiV0_2,
+    Spdx,
+    CycloneDx,
+}
+
+/// Common behavior every predicate type implements, so callers can work with
+/// a predicate without knowing its concrete schema.
+pub trait PredicateLayout {
+    /// Canonicalize this predicate into the bytes that get signed, using the
+    /// crate's default interchange (canonical JSON).
+    fn to_bytes(&self) -> Result<Vec<u8>>;
+
+    /// Fold this predicate into a [`PredicateWrapper`].
+    fn into_enum(self: Box<Self>) -> PredicateWrapper;
+
+    /// The schema version of this predicate.
+    fn version(&self) -> PredicateVersion;
+
+    /// Canonicalize this predicate through an arbitrary [`DataInterchange`],
+    /// e.g. [`crate::interchange::Cbor`] for a smaller, non-JSON encoding.
+    ///
+    /// Not dispatchable through `dyn PredicateLayout` since it's generic;
+    /// call it on the concrete predicate type instead.
+    fn to_bytes_with<D: DataInterchange>(&self) -> Result<Vec<u8>>
+    where
+        Self: Serialize + Sized,
+    {
+        D::canonicalize(&D::serialize(self)?)
+    }
+}
+
+/// A predicate of any known schema, carried alongside an in-toto statement.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PredicateWrapper {
+    LinkV0_2(LinkV02),
+    SLSAProvenanceV1(SLSAProvenanceV1),
+    ScaiV0_2(ScaiV02),
+    Spdx(SbomPredicate),
+    CycloneDx(SbomPredicate),
+}
+
+impl PredicateWrapper {
+    /// Build a predicate of the given `version` from link metadata.
+    ///
+    /// Only `LinkV0_2` can be produced this way; link metadata has no
+    /// sensible mapping onto the other predicate schemas.
+    pub fn from_meta(meta: LinkMetadata, version: PredicateVersion) -> Result<PredicateWrapper> {
+        match version {
+            PredicateVersion::LinkV0_2 => Ok(Box::new(LinkV02::from(meta)).into_enum()),
+            _ => Err(Error::Opaque(
+                "from_meta is only supported for the LinkV0_2 predicate".to_string(),
+            )),
+        }
+    }
+
+    /// Unwrap this predicate into a boxed trait object.
+    pub fn into_trait(self) -> Box<dyn PredicateLayout> {
+        match self {
+            PredicateWrapper::LinkV0_2(predicate) => Box::new(predicate),
+            PredicateWrapper::SLSAProvenanceV1(predicate) => Box::new(predicate),
+            PredicateWrapper::ScaiV0_2(predicate) => Box::new(predicate),
+            PredicateWrapper::Spdx(predicate) => Box::new(predicate),
+            PredicateWrapper::CycloneDx(predicate) => Box::new(predicate),
+        }
+    }
+
+    /// Parse `bytes` as the given predicate `version`, using the crate's
+    /// default interchange (canonical JSON).
+    ///
+    /// Parsing rejects JSON objects that repeat a key at any nesting level,
+    /// rather than silently keeping the last value as `serde_json` does by
+    /// default - see [`Json::from_bytes`](crate::interchange::Json).
+    pub fn from_bytes(bytes: &[u8], version: PredicateVersion) -> Result<PredicateWrapper> {
+        Self::from_bytes_with::<Json>(bytes, version)
+    }
+
+    /// Parse `bytes`, trying every known predicate schema in turn.
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<PredicateWrapper> {
+        Self::try_from_bytes_with::<Json>(bytes)
+    }
+
+    /// Parse `bytes` as the given predicate `version`, decoded through an
+    /// arbitrary [`DataInterchange`] (e.g. [`crate::interchange::Cbor`]).
+    ///
+    /// Every interchange is responsible for rejecting malformed documents on
+    /// its own terms - for [`Json`] that includes repeated object keys, see
+    /// [`Json::from_bytes`](crate::interchange::Json).
+    pub fn from_bytes_with<D: DataInterchange>(
+        bytes: &[u8],
+        version: PredicateVersion,
+    ) -> Result<PredicateWrapper> {
+        let raw = D::from_bytes(bytes)?;
+        match version {
+            PredicateVersion::LinkV0_2 => {
+                let predicate: LinkV02 = D::deserialize(&raw)?;
+                Ok(Box::new(predicate).into_enum())
+            }
+            PredicateVersion::SLSAProvenanceV1 => {
+                let predicate: SLSAProvenanceV1 = D::deserialize(&raw)?;
+                Ok(Box::new(predicate).into_enum())
+            }
+            PredicateVersion::ScaiV0_2 => {
+                let predicate: ScaiV02 = D::deserialize(&raw)?;
+                Ok(Box::new(predicate).into_enum())
+            }
+            PredicateVersion::Spdx | PredicateVersion::CycloneDx => {
+                let predicate: SbomPredicate = D::deserialize(&raw)?;
+                if predicate.version() != version {
+                    return Err(Error::Opaque(format!(
+                        "expected a {:?} predicate but its predicateType was `{}`",
+                        version,
+                        predicate.predicate_type()
+                    )));
+                }
+                Ok(Box::new(predicate).into_enum())
+            }
+        }
+    }
+
+    /// Parse `bytes` through interchange `D`, trying every known predicate
+    /// schema in turn.
+    pub fn try_from_bytes_with<D: DataInterchange>(bytes: &[u8]) -> Result<PredicateWrapper> {
+        Self::from_bytes_with::<D>(bytes, PredicateVersion::LinkV0_2)
+            .or_else(|_| Self::from_bytes_with::<D>(bytes, PredicateVersion::SLSAProvenanceV1))
+            .or_else(|_| Self::from_bytes_with::<D>(bytes, PredicateVersion::ScaiV0_2))
+            .or_else(|_| Self::from_bytes_with::<D>(bytes, PredicateVersion::Spdx))
+            .or_else(|_| Self::from_bytes_with::<D>(bytes, PredicateVersion::CycloneDx))
+    }
+}
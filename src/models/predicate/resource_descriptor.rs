@@ -0,0 +1,29 @@
+use std::collections::BTreeMap;
+
+use serde_derive::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// A generic reference to a piece of software, reused across predicate
+/// schemas that need to point at an artifact (in-toto's `ResourceDescriptor`).
+///
+/// <https://github.com/in-toto/attestation/blob/main/spec/v1/resource_descriptor.md>
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct ResourceDescriptor {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    uri: Option<String>,
+    digest: BTreeMap<String, String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content: Option<String>,
+    #[serde(
+        rename = "downloadLocation",
+        skip_serializing_if = "Option::is_none"
+    )]
+    download_location: Option<String>,
+    #[serde(rename = "mediaType", skip_serializing_if = "Option::is_none")]
+    media_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    annotations: Option<Value>,
+}
@@ -0,0 +1,256 @@
+use std::collections::BTreeMap;
+
+use serde::de::{self, Deserializer};
+use serde_derive::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::{PredicateLayout, PredicateVersion, PredicateWrapper};
+use crate::interchange::Json;
+use crate::Result;
+
+const SPDX_PREDICATE_TYPE: &str = "https://spdx.dev/Document";
+const CYCLONEDX_PREDICATE_TYPE: &str = "https://cyclonedx.org/bom";
+
+#[derive(Debug, Serialize, PartialEq, Clone)]
+/// Wraps a full SBOM document as an in-toto predicate, so the SBOM itself is
+/// the thing being attested to rather than just a reference to it.
+///
+/// The SBOM is kept as a validated [`serde_json::Value`] rather than a fully
+/// typed document, since SPDX and CycloneDX each define their own schema;
+/// use [`SbomPredicate::as_spdx`] to parse it into a typed [`SpdxDocument`]
+/// when `predicate_type` is the SPDX one.
+pub struct SbomPredicate {
+    #[serde(rename = "predicateType")]
+    predicate_type: String,
+    sbom: Value,
+}
+
+// `predicate_type` must be one of the two known SBOM URIs for `into_enum`/
+// `version` below to be able to classify every instance without a fallback
+// bucket, so deserializing checks it up front rather than deferring to those
+// infallible methods.
+impl<'de> serde::Deserialize<'de> for SbomPredicate {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(deny_unknown_fields)]
+        struct Raw {
+            #[serde(rename = "predicateType")]
+            predicate_type: String,
+            sbom: Value,
+        }
+
+        let raw = Raw::deserialize(deserializer)?;
+        if raw.predicate_type != SPDX_PREDICATE_TYPE && raw.predicate_type != CYCLONEDX_PREDICATE_TYPE
+        {
+            return Err(de::Error::custom(format!(
+                "unknown SBOM predicateType `{}`",
+                raw.predicate_type
+            )));
+        }
+
+        Ok(SbomPredicate {
+            predicate_type: raw.predicate_type,
+            sbom: raw.sbom,
+        })
+    }
+}
+
+impl SbomPredicate {
+    /// Wrap an SPDX 2.3 document as a predicate.
+    pub fn new_spdx(sbom: Value) -> SbomPredicate {
+        SbomPredicate {
+            predicate_type: SPDX_PREDICATE_TYPE.to_string(),
+            sbom,
+        }
+    }
+
+    /// Wrap a CycloneDX document as a predicate.
+    pub fn new_cyclonedx(sbom: Value) -> SbomPredicate {
+        SbomPredicate {
+            predicate_type: CYCLONEDX_PREDICATE_TYPE.to_string(),
+            sbom,
+        }
+    }
+
+    /// The `predicateType` URI carried alongside the raw SBOM.
+    pub fn predicate_type(&self) -> &str {
+        &self.predicate_type
+    }
+
+    /// The raw SBOM document.
+    pub fn sbom(&self) -> &Value {
+        &self.sbom
+    }
+
+    /// Parse the embedded SBOM as a typed SPDX 2.3 document.
+    pub fn as_spdx(&self) -> Result<SpdxDocument> {
+        Ok(serde_json::from_value(self.sbom.clone())?)
+    }
+}
+
+impl PredicateLayout for SbomPredicate {
+    fn to_bytes(&self) -> Result<Vec<u8>> {
+        self.to_bytes_with::<Json>()
+    }
+
+    fn into_enum(self: Box<Self>) -> PredicateWrapper {
+        match self.predicate_type.as_str() {
+            SPDX_PREDICATE_TYPE => PredicateWrapper::Spdx(*self),
+            CYCLONEDX_PREDICATE_TYPE => PredicateWrapper::CycloneDx(*self),
+            other => unreachable!("SbomPredicate constructed with unknown predicateType `{}`", other),
+        }
+    }
+
+    fn version(&self) -> PredicateVersion {
+        match self.predicate_type.as_str() {
+            SPDX_PREDICATE_TYPE => PredicateVersion::Spdx,
+            CYCLONEDX_PREDICATE_TYPE => PredicateVersion::CycloneDx,
+            other => unreachable!("SbomPredicate constructed with unknown predicateType `{}`", other),
+        }
+    }
+}
+
+/// The top-level fields of an SPDX 2.3 document.
+///
+/// <https://spdx.github.io/spdx-spec/v2.3/document-creation-information/>
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct SpdxDocument {
+    #[serde(rename = "spdxVersion")]
+    pub spdx_version: String,
+    #[serde(rename = "SPDXID")]
+    pub spdxid: String,
+    pub name: String,
+    #[serde(rename = "documentNamespace")]
+    pub document_namespace: String,
+    #[serde(rename = "creationInfo")]
+    pub creation_info: Value,
+    #[serde(default)]
+    pub packages: Vec<Value>,
+    #[serde(default)]
+    pub relationships: Vec<Value>,
+    #[serde(flatten)]
+    pub other: BTreeMap<String, Value>,
+}
+
+#[cfg(test)]
+pub mod test {
+    use std::str;
+
+    use once_cell::sync::Lazy;
+    use serde_json::json;
+
+    use super::SbomPredicate;
+    use crate::models::{PredicateLayout, PredicateVersion, PredicateWrapper};
+
+    fn sample_spdx() -> serde_json::Value {
+        json!({
+            "spdxVersion": "SPDX-2.3",
+            "SPDXID": "SPDXRef-DOCUMENT",
+            "name": "example",
+            "documentNamespace": "https://example.com/spdx/example-123",
+            "creationInfo": { "created": "2023-01-01T00:00:00Z", "creators": ["Tool: in-toto-rs"] },
+            "packages": [],
+            "relationships": [],
+        })
+    }
+
+    pub static PREDICATE_SPDX: Lazy<SbomPredicate> =
+        Lazy::new(|| SbomPredicate::new_spdx(sample_spdx()));
+
+    pub static STR_PREDICATE_SPDX: Lazy<String> = Lazy::new(|| {
+        let bytes = PREDICATE_SPDX.to_bytes().unwrap();
+        str::from_utf8(&bytes).unwrap().to_string()
+    });
+
+    #[test]
+    fn into_trait_equal() {
+        let predicate = PredicateWrapper::Spdx(PREDICATE_SPDX.clone());
+        let real = Box::new(PREDICATE_SPDX.clone()).into_enum();
+
+        assert_eq!(predicate, real);
+    }
+
+    #[test]
+    fn version_is_spdx() {
+        assert_eq!(PREDICATE_SPDX.version(), PredicateVersion::Spdx);
+    }
+
+    #[test]
+    fn version_is_cyclonedx() {
+        let predicate = SbomPredicate::new_cyclonedx(json!({"bomFormat": "CycloneDX"}));
+        assert_eq!(predicate.version(), PredicateVersion::CycloneDx);
+    }
+
+    #[test]
+    fn serialize_predicate() {
+        let predicate = Box::new(PREDICATE_SPDX.clone()).into_enum();
+        let buf = predicate.into_trait().to_bytes().unwrap();
+        let predicate_serialized = str::from_utf8(&buf).unwrap();
+
+        assert_eq!(predicate_serialized, *STR_PREDICATE_SPDX);
+    }
+
+    #[test]
+    fn deserialize_predicate() {
+        let predicate =
+            PredicateWrapper::from_bytes(STR_PREDICATE_SPDX.as_bytes(), PredicateVersion::Spdx)
+                .unwrap();
+        let real = Box::new(PREDICATE_SPDX.clone()).into_enum();
+
+        assert_eq!(predicate, real);
+    }
+
+    #[test]
+    fn deserialize_auto() {
+        let predicate = PredicateWrapper::try_from_bytes(STR_PREDICATE_SPDX.as_bytes()).unwrap();
+        let real = Box::new(PREDICATE_SPDX.clone()).into_enum();
+
+        assert_eq!(predicate, real);
+    }
+
+    #[test]
+    fn parses_typed_spdx_document() {
+        let document = PREDICATE_SPDX.as_spdx().unwrap();
+
+        assert_eq!(document.spdx_version, "SPDX-2.3");
+        assert_eq!(document.name, "example");
+    }
+
+    #[test]
+    fn deserialize_wrong_patterns() {
+        let wrong_patterns = vec!["{", "{}"];
+        for pattern in wrong_patterns {
+            let predicate = PredicateWrapper::from_bytes(pattern.as_bytes(), PredicateVersion::Spdx);
+
+            assert!(predicate.is_err());
+        }
+    }
+
+    #[test]
+    fn rejects_predicate_type_mismatch() {
+        // A well-formed SPDX document requested as CycloneDX must not be
+        // silently accepted as one.
+        let predicate =
+            PredicateWrapper::from_bytes(STR_PREDICATE_SPDX.as_bytes(), PredicateVersion::CycloneDx);
+
+        assert!(predicate.is_err());
+    }
+
+    #[test]
+    fn rejects_unknown_predicate_type() {
+        let doc = json!({
+            "predicateType": "https://totally-bogus.example/not-a-real-sbom-type",
+            "sbom": {},
+        })
+        .to_string();
+
+        let predicate = PredicateWrapper::from_bytes(doc.as_bytes(), PredicateVersion::CycloneDx);
+        assert!(predicate.is_err());
+
+        let auto = PredicateWrapper::try_from_bytes(doc.as_bytes());
+        assert!(auto.is_err());
+    }
+}
@@ -0,0 +1,132 @@
+use serde_derive::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::resource_descriptor::ResourceDescriptor;
+use super::{PredicateLayout, PredicateVersion, PredicateWrapper};
+use crate::interchange::Json;
+use crate::Result;
+
+/// A single claim about an attribute of the `target` (or of the producer, if
+/// `target` is absent), along with the evidence backing it.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct AttributeAssertion {
+    attribute: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    target: Option<ResourceDescriptor>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    conditions: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    evidence: Option<ResourceDescriptor>,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(deny_unknown_fields)]
+/// Predicate `ScaiV02` models the SCAI attribute-report schema, a set of
+/// assertions about supply-chain attributes of an artifact.
+///
+/// [SCAI v0.2](https://github.com/in-toto/attestation/tree/main/spec/predicates/scai.md)
+pub struct ScaiV02 {
+    attributes: Vec<AttributeAssertion>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    producer: Option<ResourceDescriptor>,
+}
+
+impl PredicateLayout for ScaiV02 {
+    fn to_bytes(&self) -> Result<Vec<u8>> {
+        self.to_bytes_with::<Json>()
+    }
+
+    fn into_enum(self: Box<Self>) -> PredicateWrapper {
+        PredicateWrapper::ScaiV0_2(*self)
+    }
+
+    fn version(&self) -> PredicateVersion {
+        PredicateVersion::ScaiV0_2
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use std::str;
+
+    use once_cell::sync::Lazy;
+    use serde_json::json;
+
+    use super::{AttributeAssertion, ScaiV02};
+    use crate::models::{PredicateLayout, PredicateVersion, PredicateWrapper};
+
+    pub static PREDICATE_SCAI_V02: Lazy<ScaiV02> = Lazy::new(|| ScaiV02 {
+        attributes: vec![AttributeAssertion {
+            attribute: "TRUSTED_BUILD_SYSTEM".to_string(),
+            target: Some(
+                serde_json::from_value(json!({
+                    "name": "artifact",
+                    "digest": { "sha256": "c3ab8ff13720e8ad9047dd39466b3c89" },
+                }))
+                .unwrap(),
+            ),
+            conditions: None,
+            evidence: None,
+        }],
+        producer: None,
+    });
+
+    pub static STR_PREDICATE_SCAI_V02: Lazy<String> = Lazy::new(|| {
+        let bytes = PREDICATE_SCAI_V02.to_bytes().unwrap();
+        str::from_utf8(&bytes).unwrap().to_string()
+    });
+
+    #[test]
+    fn into_trait_equal() {
+        let predicate = PredicateWrapper::ScaiV0_2(PREDICATE_SCAI_V02.clone());
+        let real = Box::new(PREDICATE_SCAI_V02.clone()).into_enum();
+
+        assert_eq!(predicate, real);
+    }
+
+    #[test]
+    fn version_is_scai_v0_2() {
+        assert_eq!(PREDICATE_SCAI_V02.version(), PredicateVersion::ScaiV0_2);
+    }
+
+    #[test]
+    fn serialize_predicate() {
+        let predicate = Box::new(PREDICATE_SCAI_V02.clone()).into_enum();
+        let buf = predicate.into_trait().to_bytes().unwrap();
+        let predicate_serialized = str::from_utf8(&buf).unwrap();
+
+        assert_eq!(predicate_serialized, *STR_PREDICATE_SCAI_V02);
+    }
+
+    #[test]
+    fn deserialize_predicate() {
+        let predicate = PredicateWrapper::from_bytes(
+            STR_PREDICATE_SCAI_V02.as_bytes(),
+            PredicateVersion::ScaiV0_2,
+        )
+        .unwrap();
+        let real = Box::new(PREDICATE_SCAI_V02.clone()).into_enum();
+
+        assert_eq!(predicate, real);
+    }
+
+    #[test]
+    fn deserialize_auto() {
+        let predicate = PredicateWrapper::try_from_bytes(STR_PREDICATE_SCAI_V02.as_bytes()).unwrap();
+        let real = Box::new(PREDICATE_SCAI_V02.clone()).into_enum();
+
+        assert_eq!(predicate, real);
+    }
+
+    #[test]
+    fn deserialize_wrong_patterns() {
+        let wrong_patterns = vec!["{", "{}"];
+        for pattern in wrong_patterns {
+            let predicate =
+                PredicateWrapper::from_bytes(pattern.as_bytes(), PredicateVersion::ScaiV0_2);
+
+            assert!(predicate.is_err());
+        }
+    }
+}
@@ -0,0 +1,200 @@
+use std::collections::BTreeMap;
+
+use serde_derive::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::resource_descriptor::ResourceDescriptor;
+use super::{PredicateLayout, PredicateVersion, PredicateWrapper};
+use crate::interchange::Json;
+use crate::Result;
+
+/// Describes how the build that produced an artifact was invoked.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct BuildDefinition {
+    #[serde(rename = "buildType")]
+    build_type: String,
+    #[serde(rename = "externalParameters")]
+    external_parameters: Value,
+    #[serde(rename = "internalParameters")]
+    internal_parameters: Value,
+    #[serde(rename = "resolvedDependencies")]
+    resolved_dependencies: Vec<ResourceDescriptor>,
+}
+
+/// Identifies the builder that carried out the build.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct Builder {
+    id: String,
+    #[serde(
+        rename = "builderDependencies",
+        skip_serializing_if = "Option::is_none"
+    )]
+    builder_dependencies: Option<Vec<ResourceDescriptor>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version: Option<BTreeMap<String, String>>,
+}
+
+/// Additional information about the invocation, timed with RFC3339 timestamps.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct BuildMetadata {
+    #[serde(rename = "invocationId", skip_serializing_if = "Option::is_none")]
+    invocation_id: Option<String>,
+    #[serde(rename = "startedOn", skip_serializing_if = "Option::is_none")]
+    started_on: Option<String>,
+    #[serde(rename = "finishedOn", skip_serializing_if = "Option::is_none")]
+    finished_on: Option<String>,
+}
+
+/// Details about the run of the `buildDefinition`.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct RunDetails {
+    builder: Builder,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    metadata: Option<BuildMetadata>,
+    byproducts: Vec<ResourceDescriptor>,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(deny_unknown_fields)]
+/// Predicate `SLSAProvenanceV1` models the SLSA Provenance v1 schema,
+/// describing how an artifact was produced.
+///
+/// [SLSA Provenance v1](https://slsa.dev/spec/v1.0/provenance)
+pub struct SLSAProvenanceV1 {
+    #[serde(rename = "buildDefinition")]
+    build_definition: BuildDefinition,
+    #[serde(rename = "runDetails")]
+    run_details: RunDetails,
+}
+
+impl PredicateLayout for SLSAProvenanceV1 {
+    fn to_bytes(&self) -> Result<Vec<u8>> {
+        self.to_bytes_with::<Json>()
+    }
+
+    fn into_enum(self: Box<Self>) -> PredicateWrapper {
+        PredicateWrapper::SLSAProvenanceV1(*self)
+    }
+
+    fn version(&self) -> PredicateVersion {
+        PredicateVersion::SLSAProvenanceV1
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use std::collections::BTreeMap;
+    use std::str;
+
+    use once_cell::sync::Lazy;
+    use serde_json::json;
+
+    use super::{BuildDefinition, BuildMetadata, Builder, RunDetails, SLSAProvenanceV1};
+    use crate::{
+        models::predicate::resource_descriptor::ResourceDescriptor,
+        models::{PredicateLayout, PredicateVersion, PredicateWrapper},
+    };
+
+    fn resource_descriptor(name: &str, digest: &str) -> ResourceDescriptor {
+        serde_json::from_value(json!({
+            "name": name,
+            "digest": { "sha256": digest },
+        }))
+        .unwrap()
+    }
+
+    pub static PREDICATE_SLSA_PROVENANCE_V1: Lazy<SLSAProvenanceV1> = Lazy::new(|| {
+        SLSAProvenanceV1 {
+            build_definition: BuildDefinition {
+                build_type: "https://example.com/buildType".to_string(),
+                external_parameters: json!({}),
+                internal_parameters: json!({}),
+                resolved_dependencies: vec![resource_descriptor(
+                    "source",
+                    "c3ab8ff13720e8ad9047dd39466b3c89",
+                )],
+            },
+            run_details: RunDetails {
+                builder: Builder {
+                    id: "https://example.com/builder".to_string(),
+                    builder_dependencies: None,
+                    version: Some(BTreeMap::new()),
+                },
+                metadata: Some(BuildMetadata {
+                    invocation_id: Some("invocation-id".to_string()),
+                    started_on: Some("2023-01-01T00:00:00Z".to_string()),
+                    finished_on: Some("2023-01-01T00:01:00Z".to_string()),
+                }),
+                byproducts: vec![],
+            },
+        }
+    });
+
+    pub static STR_PREDICATE_SLSA_PROVENANCE_V1: Lazy<String> = Lazy::new(|| {
+        let bytes = PREDICATE_SLSA_PROVENANCE_V1.to_bytes().unwrap();
+        str::from_utf8(&bytes).unwrap().to_string()
+    });
+
+    #[test]
+    fn into_trait_equal() {
+        let predicate = PredicateWrapper::SLSAProvenanceV1(PREDICATE_SLSA_PROVENANCE_V1.clone());
+        let real = Box::new(PREDICATE_SLSA_PROVENANCE_V1.clone()).into_enum();
+
+        assert_eq!(predicate, real);
+    }
+
+    #[test]
+    fn version_is_slsa_provenance_v1() {
+        assert_eq!(
+            PREDICATE_SLSA_PROVENANCE_V1.version(),
+            PredicateVersion::SLSAProvenanceV1
+        );
+    }
+
+    #[test]
+    fn serialize_predicate() {
+        let predicate = Box::new(PREDICATE_SLSA_PROVENANCE_V1.clone()).into_enum();
+        let buf = predicate.into_trait().to_bytes().unwrap();
+        let predicate_serialized = str::from_utf8(&buf).unwrap();
+
+        assert_eq!(predicate_serialized, *STR_PREDICATE_SLSA_PROVENANCE_V1);
+    }
+
+    #[test]
+    fn deserialize_predicate() {
+        let predicate = PredicateWrapper::from_bytes(
+            STR_PREDICATE_SLSA_PROVENANCE_V1.as_bytes(),
+            PredicateVersion::SLSAProvenanceV1,
+        )
+        .unwrap();
+        let real = Box::new(PREDICATE_SLSA_PROVENANCE_V1.clone()).into_enum();
+
+        assert_eq!(predicate, real);
+    }
+
+    #[test]
+    fn deserialize_auto() {
+        let predicate =
+            PredicateWrapper::try_from_bytes(STR_PREDICATE_SLSA_PROVENANCE_V1.as_bytes()).unwrap();
+        let real = Box::new(PREDICATE_SLSA_PROVENANCE_V1.clone()).into_enum();
+
+        assert_eq!(predicate, real);
+    }
+
+    #[test]
+    fn deserialize_wrong_patterns() {
+        let wrong_patterns = vec!["{", "{}"];
+        for pattern in wrong_patterns {
+            let predicate = PredicateWrapper::from_bytes(
+                pattern.as_bytes(),
+                PredicateVersion::SLSAProvenanceV1,
+            );
+
+            assert!(predicate.is_err());
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use serde_derive::{Deserialize, Serialize};
+
+/// The command a step or link ran, recorded as a single string.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(transparent)]
+pub struct Command(String);
+
+impl From<&str> for Command {
+    fn from(command: &str) -> Command {
+        Command(command.to_string())
+    }
+}
+
+impl From<String> for Command {
+    fn from(command: String) -> Command {
+        Command(command)
+    }
+}
@@ -0,0 +1,13 @@
+use once_cell::sync::Lazy;
+use std::collections::BTreeMap;
+
+use super::{byproducts::ByProducts, LinkMetadata};
+
+/// A blank [`LinkMetadata`] fixture shared by the predicate tests.
+pub static BLANK_META: Lazy<LinkMetadata> = Lazy::new(|| LinkMetadata {
+    name: "".to_string(),
+    materials: BTreeMap::new(),
+    env: None,
+    command: "".into(),
+    byproducts: ByProducts::new(),
+});